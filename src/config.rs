@@ -0,0 +1,5 @@
+/// Reads the name of the person running the CLI from `RUSTY_JIRA_USER`, so
+/// `Assign --me` doesn't require typing out a name that's already known.
+pub fn current_user() -> Option<String> {
+    std::env::var("RUSTY_JIRA_USER").ok()
+}
@@ -0,0 +1,111 @@
+use crate::store::TicketStore;
+use crate::{execute, Command};
+use rustyline::error::ReadlineError;
+use rustyline::Editor;
+use structopt::StructOpt;
+
+const HISTORY_PATH: &str = ".rusty_jira_history";
+
+/// A REPL over an already-loaded store. Each line is shell-tokenized and
+/// parsed as if it were CLI arguments, so every `Command` other than
+/// `interactive` and `migrate` works here exactly as it does one-shot
+/// (`show --ticket-id <id>` to open a ticket, `move`/
+/// `comment`/`assign` to edit it, quoted multi-word `--title`/`--description`
+/// values and all), and the board is persisted once on exit rather than once
+/// per line.
+pub fn run(ticket_store: &mut dyn TicketStore) {
+    let mut editor = Editor::<()>::new();
+    let _ = editor.load_history(HISTORY_PATH);
+
+    loop {
+        match editor.readline("rusty-jira> ") {
+            Ok(line) => {
+                let line = line.trim();
+                if line.is_empty() {
+                    continue;
+                }
+                editor.add_history_entry(line);
+                if line == "exit" || line == "quit" {
+                    break;
+                }
+                if let Err(err) = run_line(line, ticket_store) {
+                    println!("Error: {}", err);
+                }
+            }
+            Err(ReadlineError::Interrupted) | Err(ReadlineError::Eof) => break,
+            Err(err) => {
+                println!("Error: {}", err);
+                break;
+            }
+        }
+    }
+
+    let _ = editor.save_history(HISTORY_PATH);
+}
+
+fn run_line(line: &str, ticket_store: &mut dyn TicketStore) -> Result<(), Box<dyn std::error::Error>> {
+    let words = shell_words::split(line)?;
+    let tokens = std::iter::once("rusty-jira".to_string()).chain(words);
+    let command = Command::from_iter_safe(tokens)?;
+    match command {
+        // `execute` doesn't handle these two: `main` special-cases them
+        // (`migrate` before the store is even loaded). Reject them here with a normal
+        // error instead of reaching `execute`'s `unreachable!()`.
+        Command::Interactive => Err("Already in the REPL; type \"exit\" or \"quit\" to leave.".into()),
+        Command::Migrate => {
+            Err("Migrate runs against the board before it's loaded; run it from the command line instead.".into())
+        }
+        command => execute(command, ticket_store),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::run_line;
+    use crate::store::MemoryStore;
+
+    #[test]
+    fn interactive_is_rejected_instead_of_reaching_execute() {
+        let mut ticket_store = MemoryStore::new();
+
+        let result = run_line("interactive", &mut ticket_store);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn migrate_is_rejected_instead_of_reaching_execute() {
+        let mut ticket_store = MemoryStore::new();
+
+        let result = run_line("migrate", &mut ticket_store);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn an_ordinary_command_still_dispatches_to_execute() {
+        let mut ticket_store = MemoryStore::new();
+
+        let result = run_line(
+            "create --title \"Test\" --description \"Test\"",
+            &mut ticket_store,
+        );
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn a_quoted_multi_word_value_is_kept_as_one_argument() {
+        let mut ticket_store = MemoryStore::new();
+
+        let result = run_line(
+            "create --title \"Fix the login bug\" --description \"Users can't sign in\"",
+            &mut ticket_store,
+        );
+
+        assert!(result.is_ok());
+        let ticket = ticket_store.list().into_iter().next().unwrap();
+        assert_eq!(ticket.title.to_string(), "Fix the login bug");
+        assert_eq!(ticket.description, "Users can't sign in");
+    }
+}
@@ -1,11 +1,15 @@
 #![allow(clippy::new_without_default)]
 
-use crate::models::{Comment, Status, TicketDraft, TicketPatch, Title};
+use crate::models::{Assignee, Comment, Status, TicketDraft, TicketId, TicketPatch, Title};
+use crate::store::TicketStore;
 use std::error::Error;
 use std::str::FromStr;
 
+pub mod config;
+pub mod interactive;
 pub mod models;
 pub mod persistence;
+pub mod search;
 pub mod store;
 
 #[derive(structopt::StructOpt)]
@@ -18,7 +22,7 @@ pub enum Command {
     },
     Edit {
         #[structopt(long)]
-        ticket_id: u64,
+        ticket_id: TicketId,
         #[structopt(long)]
         title: Option<String>,
         #[structopt(long)]
@@ -26,43 +30,92 @@ pub enum Command {
     },
     Delete {
         #[structopt(long)]
-        ticket_id: u64,
+        ticket_id: TicketId,
+    },
+    Show {
+        #[structopt(long)]
+        ticket_id: TicketId,
+    },
+    List {
+        #[structopt(long)]
+        assignee: Option<String>,
     },
-    List,
     Move {
         #[structopt(long)]
-        ticket_id: u64,
+        ticket_id: TicketId,
         #[structopt(long)]
         status: Status,
     },
+    Search {
+        query: String,
+        #[structopt(long)]
+        status: Option<Status>,
+    },
     Comment {
         #[structopt(long)]
-        ticket_id: u64,
+        ticket_id: TicketId,
         #[structopt(long)]
         comment: String,
     },
+    Assign {
+        #[structopt(long)]
+        ticket_id: TicketId,
+        #[structopt(long)]
+        assignee: Option<String>,
+        /// Assigns the ticket to the current user, as read from
+        /// `RUSTY_JIRA_USER`, instead of requiring `--assignee` to be typed out.
+        #[structopt(long)]
+        me: bool,
+    },
+    /// Opens a REPL over the loaded board: run any of the commands above
+    /// by name, in a loop, with history and line editing, and persist once
+    /// on exit instead of once per command.
+    Interactive,
+    /// Force-runs the schema migration chain against the JSON board and
+    /// rewrites it, independent of any other command.
+    Migrate,
 }
 
 impl FromStr for Status {
-    type Err = Box<dyn Error>;
+    type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let s = s.to_lowercase();
-        let status = match s.as_str() {
-            "todo" | "to-do" => Status::ToDo,
-            "inprogress" | "in-progress" => Status::InProgress,
-            "blocked" => Status::Blocked,
-            "done" => Status::Done,
-            _ => panic!("The status you specified is not valid. Valid values: todo, inprogress, blocked and done.")
-        };
-        Ok(status)
+        let lowercased = s.to_lowercase();
+        match lowercased.as_str() {
+            "todo" | "to-do" => Ok(Status::ToDo),
+            "inprogress" | "in-progress" => Ok(Status::InProgress),
+            "blocked" => Ok(Status::Blocked),
+            "done" => Ok(Status::Done),
+            _ => Err(format!(
+                "\"{}\" is not a valid status. Valid values: todo, inprogress, blocked and done.",
+                s
+            )),
+        }
     }
 }
 
 fn main() -> Result<(), Box<dyn Error>> {
     let command = <Command as paw::ParseArgs>::parse_args()?;
 
+    if let Command::Migrate = command {
+        persistence::migrate();
+        return Ok(());
+    }
+
     let mut ticket_store = persistence::load();
+    match command {
+        Command::Interactive => interactive::run(ticket_store.as_mut()),
+        command => execute(command, ticket_store.as_mut())?,
+    }
+
+    ticket_store.flush();
+    Ok(())
+}
+
+/// Runs a single `Command` against an already-loaded store. Shared by the
+/// one-shot CLI entry point and the `Interactive` REPL, so both surfaces
+/// stay in lockstep as commands are added.
+pub fn execute(command: Command, ticket_store: &mut dyn TicketStore) -> Result<(), Box<dyn Error>> {
     match command {
         Command::Create { description, title } => {
             let draft = TicketDraft {
@@ -96,10 +149,21 @@ fn main() -> Result<(), Box<dyn Error>> {
                 ticket_id
             ),
         },
-        Command::List => {
+        Command::Show { ticket_id } => match ticket_store.get(ticket_id) {
+            Some(ticket) => println!("{}", ticket),
+            None => println!(
+                "There was no ticket associated to the ticket id {:?}",
+                ticket_id
+            ),
+        },
+        Command::List { assignee } => {
             let ticket_list = ticket_store
                 .list()
                 .into_iter()
+                .filter(|t| match &assignee {
+                    Some(name) => t.assignee.as_ref().map(Assignee::name) == Some(name.as_str()),
+                    None => true,
+                })
                 .map(|t| format!("{}", t))
                 .collect::<Vec<String>>()
                 .join("\n\n");
@@ -107,16 +171,30 @@ fn main() -> Result<(), Box<dyn Error>> {
         }
         Command::Move { ticket_id, status } => {
             match ticket_store.update_ticket_status(ticket_id, status) {
-                Some(_) => println!(
+                Some(Ok(())) => println!(
                     "Status of ticket {:?} was updated to {:?}",
                     ticket_id, status
                 ),
+                Some(Err(illegal_transition)) => println!("{}", illegal_transition),
                 None => println!(
                     "There was no ticket associated to the ticket id {:?}",
                     ticket_id
                 ),
             }
         }
+        Command::Search { query, status } => {
+            let results = ticket_store.search(&query, status);
+            if results.is_empty() {
+                println!("No tickets matched \"{}\".", query);
+            } else {
+                let ranked = results
+                    .into_iter()
+                    .map(|(ticket, score)| format!("[{:.2}] {}", score, ticket))
+                    .collect::<Vec<String>>()
+                    .join("\n\n");
+                println!("{}", ranked);
+            }
+        }
         Command::Comment { ticket_id, comment } => {
             let new_comment = Comment::new(comment)?;
             match ticket_store.add_comment_to_ticket(ticket_id, new_comment) {
@@ -127,8 +205,31 @@ fn main() -> Result<(), Box<dyn Error>> {
                 ),
             }
         }
+        Command::Assign {
+            ticket_id,
+            assignee,
+            me,
+        } => {
+            let assignee_name = match (me, assignee) {
+                (true, _) => Some(
+                    config::current_user()
+                        .ok_or("--me was passed but RUSTY_JIRA_USER is not set")?,
+                ),
+                (false, assignee) => assignee,
+            };
+            let assignee = assignee_name.map(Assignee::new).transpose()?;
+            match ticket_store.assign_ticket(ticket_id, assignee) {
+                Some(_) => println!("Ticket {:?} was assigned.", ticket_id),
+                None => println!(
+                    "There was no ticket associated to the ticket id {:?}",
+                    ticket_id
+                ),
+            }
+        }
+        Command::Interactive | Command::Migrate => {
+            unreachable!("handled by main before reaching execute")
+        }
     }
 
-    persistence::save(&ticket_store);
     Ok(())
 }
@@ -0,0 +1,131 @@
+mod ticket;
+
+pub use ticket::{DeletedTicket, IllegalTransition, Status, Ticket, TicketId};
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(PartialEq, Eq, Debug, Clone, Hash, Serialize, Deserialize)]
+pub struct Title(String);
+
+impl Title {
+    pub fn new(title: String) -> Result<Self, String> {
+        if title.trim().is_empty() {
+            return Err("A title cannot be empty.".to_string());
+        }
+        Ok(Self(title))
+    }
+}
+
+impl fmt::Display for Title {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Hash, Serialize, Deserialize)]
+pub struct Comment(String);
+
+impl Comment {
+    pub fn new(comment: String) -> Result<Self, String> {
+        if comment.trim().is_empty() {
+            return Err("A comment cannot be empty.".to_string());
+        }
+        Ok(Self(comment))
+    }
+}
+
+impl fmt::Display for Comment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A person tickets can be assigned to. `id` is derived from `name` via
+/// `slugify`, so re-entering the same name with different capitalisation or
+/// punctuation still resolves to the same id. There is no central people
+/// registry: renaming a person means re-assigning their tickets to a new
+/// `Assignee`, since nothing remembers the old name was the same person.
+#[derive(PartialEq, Eq, Debug, Clone, Hash, Serialize, Deserialize)]
+pub struct Assignee {
+    id: String,
+    name: String,
+}
+
+impl Assignee {
+    pub fn new(name: String) -> Result<Self, String> {
+        if name.trim().is_empty() {
+            return Err("An assignee's name cannot be empty.".to_string());
+        }
+        Ok(Self {
+            id: slugify(&name),
+            name,
+        })
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Reconstructs an `Assignee` from its already-validated stored parts,
+    /// bypassing `slugify` so a backend can round-trip the id it persisted.
+    pub(crate) fn from_parts(id: String, name: String) -> Self {
+        Self { id, name }
+    }
+}
+
+impl fmt::Display for Assignee {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+fn slugify(name: &str) -> String {
+    name.trim()
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '-' })
+        .collect()
+}
+
+#[derive(Debug, Clone)]
+pub struct TicketDraft {
+    pub title: Title,
+    pub description: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct TicketPatch {
+    pub title: Option<Title>,
+    pub description: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Assignee;
+
+    #[test]
+    fn an_empty_name_is_rejected() {
+        assert!(Assignee::new("   ".to_string()).is_err());
+    }
+
+    #[test]
+    fn the_same_name_always_slugifies_to_the_same_id() {
+        let first = Assignee::new("Ada Lovelace".to_string()).expect("Failed to get an assignee");
+        let second = Assignee::new("ada lovelace".to_string()).expect("Failed to get an assignee");
+
+        assert_eq!(first.id(), second.id());
+    }
+
+    #[test]
+    fn renaming_a_person_produces_a_different_id() {
+        let before = Assignee::new("Ada Lovelace".to_string()).expect("Failed to get an assignee");
+        let after = Assignee::new("Ada L".to_string()).expect("Failed to get an assignee");
+
+        assert_ne!(before.id(), after.id());
+    }
+}
@@ -1,9 +1,12 @@
-use crate::models::{Comment, Title};
+use crate::models::{Assignee, Comment, Title};
 use serde::export::fmt::Error;
 use serde::export::Formatter;
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
-pub type TicketId = u64;
+/// Time-sortable: the most significant bits are a millisecond timestamp, so
+/// byte/lexicographic ordering of `TicketId`s equals creation order.
+pub type TicketId = Uuid;
 
 #[derive(PartialEq, Debug, Clone, Hash, Eq)]
 #[derive(Serialize, Deserialize)]
@@ -13,14 +16,19 @@ pub struct Ticket {
     pub description: String,
     pub status: Status,
     pub comments: Vec<Comment>,
+    pub assignee: Option<Assignee>,
 }
 
 impl std::fmt::Display for Ticket {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let assignee = match &self.assignee {
+            Some(assignee) => assignee.to_string(),
+            None => "Unassigned".to_string(),
+        };
         writeln!(
             f,
-            "Ticket:\n\tId:{:?}\n\tTitle:{}\n\tDescription:{}\n\tStatus:{:?}\n\tComments:",
-            self.id, self.title, self.description, self.status
+            "Ticket:\n\tId:{:?}\n\tTitle:{}\n\tDescription:{}\n\tStatus:{:?}\n\tAssignee:{}\n\tComments:",
+            self.id, self.title, self.description, self.status, assignee
         )?;
         for comment in self.comments.iter() {
             writeln!(f, "\t- {}", comment)?;
@@ -37,5 +45,44 @@ pub enum Status {
     Done,
 }
 
+impl Status {
+    /// The statuses a ticket currently in this status is allowed to move to.
+    pub fn allowed_transitions(self) -> &'static [Status] {
+        match self {
+            Status::ToDo => &[Status::InProgress],
+            Status::InProgress => &[Status::Blocked, Status::Done],
+            Status::Blocked => &[Status::InProgress],
+            Status::Done => &[Status::InProgress],
+        }
+    }
+
+    pub fn can_transition_to(self, next: Status) -> bool {
+        self.allowed_transitions().contains(&next)
+    }
+}
+
+/// A `Move` that isn't in `Status::allowed_transitions` for the ticket's
+/// current status, e.g. jumping straight from `ToDo` to `Done`.
+#[derive(PartialEq, Debug)]
+pub struct IllegalTransition {
+    pub from: Status,
+    pub to: Status,
+}
+
+impl std::fmt::Display for IllegalTransition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(
+            f,
+            "Cannot move a ticket from {:?} to {:?}; {:?} can only move to {:?}.",
+            self.from,
+            self.to,
+            self.from,
+            self.from.allowed_transitions()
+        )
+    }
+}
+
+impl std::error::Error for IllegalTransition {}
+
 #[derive(PartialEq, Debug)]
 pub struct DeletedTicket(pub Ticket);
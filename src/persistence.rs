@@ -0,0 +1,253 @@
+use crate::models::{Comment, Status, Ticket, Title};
+use crate::store::{remint_ids_preserving_order, MemoryStore, SqliteStore, TicketStore};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs;
+
+const JSON_STORE_PATH: &str = "tickets.json";
+const SQLITE_STORE_PATH: &str = "tickets.db";
+
+/// The current on-disk schema version. Bump this and add a migration
+/// function to `MIGRATIONS` whenever `Ticket`/`Status`/`Comment` gains or
+/// changes a field, so older boards keep loading instead of silently
+/// failing to deserialize.
+const CURRENT_VERSION: u32 = 2;
+
+/// A migration from the serde representation of one version to the next.
+/// Registered in `MIGRATIONS` at index `version - 1`.
+type Migration = fn(Value) -> Value;
+
+const MIGRATIONS: &[Migration] = &[migrate_v1_to_v2];
+
+/// Version 2 added the optional `assignee` field to every ticket.
+fn migrate_v1_to_v2(mut data: Value) -> Value {
+    if let Some(tickets) = data.as_object_mut() {
+        for ticket in tickets.values_mut() {
+            if let Some(ticket) = ticket.as_object_mut() {
+                ticket.entry("assignee").or_insert(Value::Null);
+            }
+        }
+    }
+    data
+}
+
+/// Envelope wrapping the serialized store with the schema version it was
+/// written under, so `load` can tell how far a board needs to be migrated
+/// before it matches the current model.
+#[derive(Serialize, Deserialize)]
+struct Envelope {
+    version: u32,
+    data: Value,
+}
+
+/// Which backend a run should use. Read from `RUSTY_JIRA_BACKEND`; defaults
+/// to the JSON-file `MemoryStore` so existing boards keep working untouched.
+enum Backend {
+    Memory,
+    Sqlite,
+}
+
+impl Backend {
+    fn from_env() -> Self {
+        match std::env::var("RUSTY_JIRA_BACKEND") {
+            Ok(value) if value.eq_ignore_ascii_case("sqlite") => Backend::Sqlite,
+            _ => Backend::Memory,
+        }
+    }
+}
+
+/// The pre-UUID on-disk shape: tickets keyed by a monotonic `u64`, with the
+/// next id to hand out tracked alongside them. Predates the envelope
+/// entirely, so it's detected and migrated before versioning even applies.
+#[derive(Deserialize)]
+struct LegacyTicketStore {
+    #[allow(dead_code)]
+    current_id: u64,
+    data: HashMap<u64, LegacyTicket>,
+}
+
+#[derive(Deserialize)]
+struct LegacyTicket {
+    title: Title,
+    description: String,
+    status: Status,
+    comments: Vec<Comment>,
+}
+
+pub fn load() -> Box<dyn TicketStore> {
+    match Backend::from_env() {
+        Backend::Sqlite => Box::new(SqliteStore::connect(SQLITE_STORE_PATH)),
+        Backend::Memory => Box::new(load_memory_store()),
+    }
+}
+
+/// Force-runs the migration chain against the JSON board and rewrites it,
+/// even if it was already loaded (and so already migrated) since. Useful to
+/// upgrade a board on disk without also running a mutating command.
+///
+/// A no-op if `tickets.json` doesn't exist: a fresh install, or one that's
+/// only ever run with `RUSTY_JIRA_BACKEND=sqlite`, has nothing to migrate,
+/// and shouldn't have this conjure an empty JSON board into existence.
+pub fn migrate() {
+    if !std::path::Path::new(JSON_STORE_PATH).exists() {
+        println!("No {} found; nothing to migrate.", JSON_STORE_PATH);
+        return;
+    }
+
+    let store = load_memory_store();
+    save_memory_store(&store);
+    println!("Board is at version {} (current).", CURRENT_VERSION);
+}
+
+fn load_memory_store() -> MemoryStore {
+    let contents = match fs::read_to_string(JSON_STORE_PATH) {
+        Ok(contents) => contents,
+        Err(_) => return MemoryStore::new(),
+    };
+
+    let raw: Value = match serde_json::from_str(&contents) {
+        Ok(raw) => raw,
+        Err(_) => return MemoryStore::new(),
+    };
+
+    if let Ok(legacy) = serde_json::from_value::<LegacyTicketStore>(raw.clone()) {
+        let migrated = migrate_legacy_store(legacy);
+        save_memory_store(&migrated);
+        return migrated;
+    }
+
+    let (version, store) = migrate_raw(raw);
+    if version < CURRENT_VERSION {
+        save_memory_store(&store);
+    }
+    store
+}
+
+/// Migrates a parsed JSON board, envelope or not, up to `CURRENT_VERSION`
+/// and returns the version it started at alongside the resulting store.
+/// Split out from `load_memory_store` so the migration chain can be
+/// exercised directly, without going through the filesystem.
+fn migrate_raw(raw: Value) -> (u32, MemoryStore) {
+    // A board saved before the envelope existed has no `version` tag and is
+    // implicitly version 1. Either way, `MemoryStore`'s own serde shape is
+    // `{"data": {"<uuid>": {...ticket...}, ...}}`, so the tickets map a
+    // migration actually needs to touch lives under that same `"data"` key
+    // whether or not an envelope wraps it.
+    let version = raw
+        .get("version")
+        .and_then(Value::as_u64)
+        .map_or(1, |version| version as u32);
+    let tickets = raw.get("data").cloned().unwrap_or(Value::Null);
+
+    let tickets = run_migrations(version, tickets);
+    let store: MemoryStore = serde_json::from_value(serde_json::json!({ "data": tickets }))
+        .expect("Migrated data should match the current model");
+
+    (version, store)
+}
+
+fn run_migrations(mut version: u32, mut data: Value) -> Value {
+    while version < CURRENT_VERSION {
+        let migration = MIGRATIONS[(version - 1) as usize];
+        data = migration(data);
+        version += 1;
+    }
+    data
+}
+
+pub(crate) fn save_memory_store(store: &MemoryStore) {
+    // `MemoryStore` serializes as `{"data": {...tickets...}}`; the envelope's
+    // own `data` holds just that tickets map, which is what `migrate_raw` reads.
+    let store_value = serde_json::to_value(store).expect("Failed to serialize ticket store");
+    let envelope = Envelope {
+        version: CURRENT_VERSION,
+        data: store_value.get("data").cloned().unwrap_or(Value::Null),
+    };
+    let contents = serde_json::to_string_pretty(&envelope).expect("Failed to serialize envelope");
+    fs::write(JSON_STORE_PATH, contents).expect("Failed to save ticket store");
+}
+
+/// Reminds every legacy ticket a fresh, time-sortable `TicketId`, preserving
+/// the order the old numeric ids implied so `list` still comes out in
+/// creation order after the migration.
+fn migrate_legacy_store(legacy: LegacyTicketStore) -> MemoryStore {
+    let mut old_ids: Vec<u64> = legacy.data.keys().copied().collect();
+    old_ids.sort_unstable();
+
+    let new_ids = remint_ids_preserving_order(old_ids.len());
+
+    let tickets = old_ids
+        .into_iter()
+        .zip(new_ids)
+        .map(|(old_id, new_id)| {
+            let legacy_ticket = legacy
+                .data
+                .get(&old_id)
+                .expect("old id came from this map's own keys");
+            Ticket {
+                id: new_id,
+                title: legacy_ticket.title.clone(),
+                description: legacy_ticket.description.clone(),
+                status: legacy_ticket.status,
+                comments: legacy_ticket.comments.clone(),
+                assignee: None,
+            }
+        })
+        .collect();
+
+    MemoryStore::from_tickets(tickets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{migrate_raw, CURRENT_VERSION};
+    use crate::store::TicketStore;
+
+    #[test]
+    fn migrating_a_pre_envelope_board_inserts_assignee_into_each_ticket() {
+        // Shaped exactly like a v1 board saved before the envelope existed:
+        // `MemoryStore`'s own derive gives it a `"data"` key, but there is no
+        // top-level `"version"` tag, and no `assignee` field on the ticket.
+        let raw = serde_json::json!({
+            "data": {
+                "01890a5d-ac96-7000-8000-000000000000": {
+                    "id": "01890a5d-ac96-7000-8000-000000000000",
+                    "title": "Write the migration",
+                    "description": "Make old boards load again",
+                    "status": "ToDo",
+                    "comments": []
+                }
+            }
+        });
+
+        let (version, store) = migrate_raw(raw);
+
+        assert_eq!(version, 1);
+        let tickets = store.list();
+        assert_eq!(tickets.len(), 1);
+        assert_eq!(tickets[0].assignee, None);
+    }
+
+    #[test]
+    fn migrating_an_up_to_date_envelope_is_a_no_op() {
+        let raw = serde_json::json!({
+            "version": CURRENT_VERSION,
+            "data": {
+                "01890a5d-ac96-7000-8000-000000000000": {
+                    "id": "01890a5d-ac96-7000-8000-000000000000",
+                    "title": "Already migrated",
+                    "description": "Nothing to do",
+                    "status": "Done",
+                    "comments": [],
+                    "assignee": null
+                }
+            }
+        });
+
+        let (version, store) = migrate_raw(raw);
+
+        assert_eq!(version, CURRENT_VERSION);
+        assert_eq!(store.list().len(), 1);
+    }
+}
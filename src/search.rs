@@ -0,0 +1,131 @@
+use crate::models::Ticket;
+use std::collections::HashMap;
+
+/// An in-process, rebuilt-on-demand search index over a ticket board: terms
+/// map to which tickets mention them and how often, which is all a TF-IDF
+/// score needs. No persistence, so it costs nothing to rebuild whenever a
+/// `Search` command runs.
+pub struct SearchIndex {
+    tickets: Vec<Option<Ticket>>,
+    postings: HashMap<String, HashMap<usize, usize>>,
+}
+
+impl SearchIndex {
+    pub fn build(tickets: Vec<Ticket>) -> Self {
+        let mut postings: HashMap<String, HashMap<usize, usize>> = HashMap::new();
+        for (index, ticket) in tickets.iter().enumerate() {
+            for term in tokenize(&searchable_text(ticket)) {
+                *postings.entry(term).or_default().entry(index).or_insert(0) += 1;
+            }
+        }
+        Self {
+            tickets: tickets.into_iter().map(Some).collect(),
+            postings,
+        }
+    }
+
+    /// Scores each ticket by summing, over every query term it contains,
+    /// `term frequency in the ticket * log(total tickets / tickets containing the term)`,
+    /// then returns matches sorted by descending score.
+    pub fn search(mut self, query: &str) -> Vec<(Ticket, f32)> {
+        let total_tickets = self.tickets.len() as f32;
+        let mut scores: HashMap<usize, f32> = HashMap::new();
+
+        for term in tokenize(query) {
+            let Some(postings) = self.postings.get(&term) else {
+                continue;
+            };
+            let idf = (total_tickets / postings.len() as f32).ln();
+            for (&ticket_index, &term_frequency) in postings {
+                *scores.entry(ticket_index).or_insert(0.0) += term_frequency as f32 * idf;
+            }
+        }
+
+        let mut ranked: Vec<(usize, f32)> = scores.into_iter().collect();
+        ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).expect("TF-IDF scores are never NaN"));
+
+        ranked
+            .into_iter()
+            .map(|(index, score)| {
+                let ticket = self.tickets[index]
+                    .take()
+                    .expect("each matching index is only ever scored once");
+                (ticket, score)
+            })
+            .collect()
+    }
+}
+
+fn tokenize(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|term| !term.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn searchable_text(ticket: &Ticket) -> String {
+    let mut text = format!("{} {}", ticket.title, ticket.description);
+    for comment in &ticket.comments {
+        text.push(' ');
+        text.push_str(&comment.to_string());
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SearchIndex;
+    use crate::models::{Status, Ticket, Title};
+    use crate::store::generate_id;
+
+    fn make_ticket(title: &str, description: &str) -> Ticket {
+        Ticket {
+            id: generate_id(),
+            title: Title::new(title.to_string()).expect("Failed to get a title"),
+            description: description.to_string(),
+            status: Status::ToDo,
+            comments: Vec::new(),
+            assignee: None,
+        }
+    }
+
+    #[test]
+    fn a_ticket_not_mentioning_the_query_is_not_returned() {
+        let tickets = vec![make_ticket("Fix the login bug", "Users can't sign in")];
+
+        let results = SearchIndex::build(tickets).search("database");
+
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn a_ticket_matching_more_query_terms_is_ranked_above_a_partial_match() {
+        // `database` alone carries no idf when every candidate contains it,
+        // so the partial-match ticket also mentions `database` and just
+        // leaves out `migration` - that's what lets its score differ from
+        // the strong match instead of tying at zero.
+        let tickets = vec![
+            make_ticket(
+                "Database migration",
+                "The database migration needs database migration work, urgent migration",
+            ),
+            make_ticket("Database notes", "A minor database note, nothing about migrations"),
+        ];
+
+        let results = SearchIndex::build(tickets).search("database migration");
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0.title, Title::new("Database migration".to_string()).unwrap());
+        assert!(results[0].1 > results[1].1);
+    }
+
+    #[test]
+    fn search_is_case_insensitive() {
+        let tickets = vec![make_ticket("URGENT Outage", "The site is DOWN")];
+
+        let results = SearchIndex::build(tickets).search("urgent outage");
+
+        assert_eq!(results.len(), 1);
+    }
+}
@@ -0,0 +1,83 @@
+pub mod memory;
+pub mod sqlite;
+
+pub use memory::MemoryStore;
+pub use sqlite::SqliteStore;
+
+use crate::models::{
+    Assignee, Comment, DeletedTicket, IllegalTransition, Status, Ticket, TicketDraft, TicketId,
+    TicketPatch,
+};
+use crate::search::SearchIndex;
+
+/// The operations every ticket-board backend must support. `persistence`
+/// picks which implementation backs a given run; callers only ever see
+/// `Box<dyn TicketStore>` and don't need to know whether tickets live in a
+/// JSON file or a SQLite database.
+pub trait TicketStore {
+    fn create(&mut self, draft: TicketDraft) -> TicketId;
+    fn delete(&mut self, id: TicketId) -> Option<DeletedTicket>;
+    fn list(&self) -> Vec<Ticket>;
+    fn get(&self, id: TicketId) -> Option<Ticket>;
+    fn update_ticket(&mut self, id: TicketId, patch: TicketPatch) -> Option<()>;
+    fn update_ticket_status(
+        &mut self,
+        id: TicketId,
+        status: Status,
+    ) -> Option<Result<(), IllegalTransition>>;
+    fn add_comment_to_ticket(&mut self, id: TicketId, comment: Comment) -> Option<()>;
+    fn assign_ticket(&mut self, id: TicketId, assignee: Option<Assignee>) -> Option<()>;
+
+    /// Persists any state the backend buffers in memory. `SqliteStore`
+    /// writes through on every mutating call above, so this is a no-op for
+    /// it; `MemoryStore` uses it to flush the whole board to disk.
+    fn flush(&self) {}
+
+    /// Ranks tickets by TF-IDF relevance to `query` over their title,
+    /// description, and comments, optionally narrowed to one `status`.
+    /// Built once, on top of `list`, so no backend has to implement its own
+    /// search scoring.
+    fn search(&self, query: &str, status: Option<Status>) -> Vec<(Ticket, f32)> {
+        let tickets = self
+            .list()
+            .into_iter()
+            .filter(|ticket| status.map_or(true, |status| ticket.status == status))
+            .collect();
+        SearchIndex::build(tickets).search(query)
+    }
+}
+
+/// Generates a UUID version 7: a 48-bit big-endian millisecond timestamp,
+/// followed by the version/variant bits, followed by random data. The
+/// timestamp occupies the most significant bits, so ordering `TicketId`s
+/// lexicographically also orders them by creation time.
+pub(crate) fn generate_id() -> TicketId {
+    uuid_v7_from_millis(current_millis(), &mut rand::thread_rng())
+}
+
+fn current_millis() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("system clock is set before the Unix epoch")
+        .as_millis() as u64
+}
+
+fn uuid_v7_from_millis(millis: u64, rng: &mut impl rand::RngCore) -> TicketId {
+    let mut bytes = [0u8; 16];
+    bytes[0..6].copy_from_slice(&millis.to_be_bytes()[2..8]);
+    rng.fill_bytes(&mut bytes[6..16]);
+    bytes[6] = (bytes[6] & 0x0F) | 0x70; // version 7
+    bytes[8] = (bytes[8] & 0x3F) | 0x80; // RFC 4122 variant
+    TicketId::from_bytes(bytes)
+}
+
+/// Reminted ids for tickets migrated from the legacy `u64`-keyed format,
+/// spaced one millisecond apart in their original (ascending `u64` id)
+/// insertion order so the new ids sort identically to the old ones.
+pub(crate) fn remint_ids_preserving_order(count: usize) -> Vec<TicketId> {
+    let base_millis = current_millis();
+    let mut rng = rand::thread_rng();
+    (0..count)
+        .map(|i| uuid_v7_from_millis(base_millis + i as u64, &mut rng))
+        .collect()
+}
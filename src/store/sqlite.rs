@@ -0,0 +1,439 @@
+use crate::models::{
+    Assignee, Comment, DeletedTicket, IllegalTransition, Status, Ticket, TicketDraft, TicketId,
+    TicketPatch,
+};
+use crate::store::{generate_id, TicketStore as TicketStoreTrait};
+use sqlx::sqlite::SqlitePoolOptions;
+use sqlx::{Row, SqlitePool};
+use tokio::runtime::Runtime;
+
+/// A backend that writes each mutation straight to a SQLite database
+/// instead of rewriting one in-memory blob on every command. Tickets,
+/// their status, and their comments live in normalized tables, so a
+/// `Comment` or a `Move` only ever touches the rows it changes.
+///
+/// Call sites stay synchronous: `SqliteStore` owns a Tokio runtime (the
+/// default one from `Runtime::new`) and drives `sqlx`'s async driver
+/// underneath it.
+pub struct SqliteStore {
+    pool: SqlitePool,
+    runtime: Runtime,
+}
+
+impl SqliteStore {
+    pub fn connect(database_path: &str) -> Self {
+        let runtime = Runtime::new().expect("Failed to start the SQLite runtime");
+        let pool = runtime.block_on(Self::open(database_path));
+        Self { pool, runtime }
+    }
+
+    async fn open(database_path: &str) -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(5)
+            .connect(&format!("sqlite://{}?mode=rwc", database_path))
+            .await
+            .expect("Failed to connect to the SQLite database");
+
+        sqlx::query(
+            "CREATE TABLE IF NOT EXISTS tickets (
+                id TEXT PRIMARY KEY,
+                title TEXT NOT NULL,
+                description TEXT NOT NULL,
+                status TEXT NOT NULL
+            )",
+        )
+        .execute(&pool)
+        .await
+        .expect("Failed to create the tickets table");
+
+        sqlx::query(
+            "CREATE TABLE IF NOT EXISTS comments (
+                ticket_id TEXT NOT NULL REFERENCES tickets(id) ON DELETE CASCADE,
+                position INTEGER NOT NULL,
+                body TEXT NOT NULL
+            )",
+        )
+        .execute(&pool)
+        .await
+        .expect("Failed to create the comments table");
+
+        Self::migrate_assignee_columns(&pool).await;
+
+        pool
+    }
+
+    /// `CREATE TABLE IF NOT EXISTS` only creates the table the first time a
+    /// database is opened, so a `tickets.db` created before assignees
+    /// existed has no `assignee_id`/`assignee_name` columns. Add them here
+    /// if they're missing, instead of baking them into the `CREATE TABLE`
+    /// above, so pre-existing databases keep working.
+    async fn migrate_assignee_columns(pool: &SqlitePool) {
+        let columns = sqlx::query("PRAGMA table_info(tickets)")
+            .fetch_all(pool)
+            .await
+            .expect("Failed to inspect the tickets table");
+        let has_assignee_id = columns
+            .iter()
+            .any(|row| row.get::<String, _>("name") == "assignee_id");
+
+        if !has_assignee_id {
+            sqlx::query("ALTER TABLE tickets ADD COLUMN assignee_id TEXT")
+                .execute(pool)
+                .await
+                .expect("Failed to add the assignee_id column");
+            sqlx::query("ALTER TABLE tickets ADD COLUMN assignee_name TEXT")
+                .execute(pool)
+                .await
+                .expect("Failed to add the assignee_name column");
+        }
+    }
+
+    async fn fetch_ticket(pool: &SqlitePool, id: TicketId) -> Option<Ticket> {
+        let row = sqlx::query(
+            "SELECT title, description, status, assignee_id, assignee_name FROM tickets WHERE id = ?",
+        )
+        .bind(id.to_string())
+        .fetch_optional(pool)
+        .await
+        .expect("Failed to query the tickets table")?;
+
+        let comments = sqlx::query("SELECT body FROM comments WHERE ticket_id = ? ORDER BY position")
+            .bind(id.to_string())
+            .fetch_all(pool)
+            .await
+            .expect("Failed to query the comments table")
+            .into_iter()
+            .map(|row| Comment::new(row.get("body")).expect("Stored comments are never empty"))
+            .collect();
+
+        let assignee = match (
+            row.get::<Option<String>, _>("assignee_id"),
+            row.get::<Option<String>, _>("assignee_name"),
+        ) {
+            (Some(id), Some(name)) => Some(Assignee::from_parts(id, name)),
+            _ => None,
+        };
+
+        Some(Ticket {
+            id,
+            title: crate::models::Title::new(row.get("title")).expect("Stored titles are never empty"),
+            description: row.get("description"),
+            status: status_from_column(row.get("status")),
+            comments,
+            assignee,
+        })
+    }
+}
+
+impl TicketStoreTrait for SqliteStore {
+    fn create(&mut self, draft: TicketDraft) -> TicketId {
+        let id = generate_id();
+        self.runtime.block_on(async {
+            sqlx::query("INSERT INTO tickets (id, title, description, status) VALUES (?, ?, ?, ?)")
+                .bind(id.to_string())
+                .bind(draft.title.to_string())
+                .bind(&draft.description)
+                .bind(status_to_column(Status::ToDo))
+                .execute(&self.pool)
+                .await
+                .expect("Failed to insert the ticket");
+        });
+        id
+    }
+
+    fn delete(&mut self, id: TicketId) -> Option<DeletedTicket> {
+        let ticket = self.runtime.block_on(Self::fetch_ticket(&self.pool, id))?;
+        self.runtime.block_on(async {
+            sqlx::query("DELETE FROM comments WHERE ticket_id = ?")
+                .bind(id.to_string())
+                .execute(&self.pool)
+                .await
+                .expect("Failed to delete the ticket's comments");
+            sqlx::query("DELETE FROM tickets WHERE id = ?")
+                .bind(id.to_string())
+                .execute(&self.pool)
+                .await
+                .expect("Failed to delete the ticket");
+        });
+        Some(DeletedTicket(ticket))
+    }
+
+    fn list(&self) -> Vec<Ticket> {
+        self.runtime.block_on(async {
+            let ids: Vec<String> = sqlx::query("SELECT id FROM tickets ORDER BY id")
+                .fetch_all(&self.pool)
+                .await
+                .expect("Failed to query the tickets table")
+                .into_iter()
+                .map(|row| row.get("id"))
+                .collect();
+
+            let mut tickets = Vec::with_capacity(ids.len());
+            for id in ids {
+                let id: TicketId = id.parse().expect("Stored ticket ids are always valid UUIDs");
+                if let Some(ticket) = Self::fetch_ticket(&self.pool, id).await {
+                    tickets.push(ticket);
+                }
+            }
+            tickets
+        })
+    }
+
+    fn get(&self, id: TicketId) -> Option<Ticket> {
+        self.runtime.block_on(Self::fetch_ticket(&self.pool, id))
+    }
+
+    fn update_ticket(&mut self, id: TicketId, patch: TicketPatch) -> Option<()> {
+        self.runtime.block_on(async {
+            if Self::fetch_ticket(&self.pool, id).await.is_none() {
+                return None;
+            }
+            if let Some(title) = patch.title {
+                sqlx::query("UPDATE tickets SET title = ? WHERE id = ?")
+                    .bind(title.to_string())
+                    .bind(id.to_string())
+                    .execute(&self.pool)
+                    .await
+                    .expect("Failed to update the ticket's title");
+            }
+            if let Some(description) = patch.description {
+                sqlx::query("UPDATE tickets SET description = ? WHERE id = ?")
+                    .bind(description)
+                    .bind(id.to_string())
+                    .execute(&self.pool)
+                    .await
+                    .expect("Failed to update the ticket's description");
+            }
+            Some(())
+        })
+    }
+
+    fn update_ticket_status(
+        &mut self,
+        id: TicketId,
+        status: Status,
+    ) -> Option<Result<(), IllegalTransition>> {
+        self.runtime.block_on(async {
+            let ticket = Self::fetch_ticket(&self.pool, id).await?;
+            if !ticket.status.can_transition_to(status) {
+                return Some(Err(IllegalTransition {
+                    from: ticket.status,
+                    to: status,
+                }));
+            }
+            sqlx::query("UPDATE tickets SET status = ? WHERE id = ?")
+                .bind(status_to_column(status))
+                .bind(id.to_string())
+                .execute(&self.pool)
+                .await
+                .expect("Failed to update the ticket's status");
+            Some(Ok(()))
+        })
+    }
+
+    fn add_comment_to_ticket(&mut self, id: TicketId, comment: Comment) -> Option<()> {
+        self.runtime.block_on(async {
+            if Self::fetch_ticket(&self.pool, id).await.is_none() {
+                return None;
+            }
+            let position: i64 = sqlx::query("SELECT COUNT(*) AS count FROM comments WHERE ticket_id = ?")
+                .bind(id.to_string())
+                .fetch_one(&self.pool)
+                .await
+                .expect("Failed to count the ticket's comments")
+                .get("count");
+            sqlx::query("INSERT INTO comments (ticket_id, position, body) VALUES (?, ?, ?)")
+                .bind(id.to_string())
+                .bind(position)
+                .bind(comment.to_string())
+                .execute(&self.pool)
+                .await
+                .expect("Failed to insert the comment");
+            Some(())
+        })
+    }
+
+    fn assign_ticket(&mut self, id: TicketId, assignee: Option<Assignee>) -> Option<()> {
+        self.runtime.block_on(async {
+            if Self::fetch_ticket(&self.pool, id).await.is_none() {
+                return None;
+            }
+            let (assignee_id, assignee_name) = match &assignee {
+                Some(assignee) => (Some(assignee.id().to_string()), Some(assignee.name().to_string())),
+                None => (None, None),
+            };
+            sqlx::query("UPDATE tickets SET assignee_id = ?, assignee_name = ? WHERE id = ?")
+                .bind(assignee_id)
+                .bind(assignee_name)
+                .bind(id.to_string())
+                .execute(&self.pool)
+                .await
+                .expect("Failed to update the ticket's assignee");
+            Some(())
+        })
+    }
+}
+
+fn status_to_column(status: Status) -> &'static str {
+    match status {
+        Status::ToDo => "todo",
+        Status::InProgress => "in-progress",
+        Status::Blocked => "blocked",
+        Status::Done => "done",
+    }
+}
+
+fn status_from_column(column: String) -> Status {
+    column
+        .parse()
+        .unwrap_or_else(|_| panic!("Unrecognised status stored in the database: {}", column))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SqliteStore;
+    use crate::models::{Assignee, Status, TicketDraft, TicketPatch, Title};
+    use crate::store::TicketStore as TicketStoreTrait;
+    use fake::{Fake, Faker};
+
+    /// Each test opens its own on-disk database under a unique path, since
+    /// `SqliteStore` always talks to a file rather than an in-memory
+    /// connection string.
+    fn open_test_store(test_name: &str) -> SqliteStore {
+        let path = std::env::temp_dir().join(format!(
+            "rusty_jira_test_{}_{}.db",
+            test_name,
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        SqliteStore::connect(path.to_str().expect("Temp path should be valid UTF-8"))
+    }
+
+    #[test]
+    fn create_and_get_round_trip() {
+        let mut store = open_test_store("create_and_get_round_trip");
+        let draft = TicketDraft {
+            title: Title::new(Faker.fake()).expect("Failed to get a title"),
+            description: Faker.fake(),
+        };
+
+        let id = store.create(draft.clone());
+        let ticket = store.get(id).expect("Failed to retrieve ticket");
+
+        assert_eq!(ticket.title, draft.title);
+        assert_eq!(ticket.description, draft.description);
+        assert_eq!(ticket.status, Status::ToDo);
+        assert_eq!(ticket.assignee, None);
+    }
+
+    #[test]
+    fn update_ticket_applies_the_patch() {
+        let mut store = open_test_store("update_ticket_applies_the_patch");
+        let draft = TicketDraft {
+            title: Title::new(Faker.fake()).expect("Failed to get a title"),
+            description: Faker.fake(),
+        };
+        let id = store.create(draft);
+        let patch = TicketPatch {
+            title: Some(Title::new("Updated title".to_string()).unwrap()),
+            description: None,
+        };
+
+        store.update_ticket(id, patch);
+
+        let ticket = store.get(id).expect("Failed to retrieve ticket");
+        assert_eq!(ticket.title, Title::new("Updated title".to_string()).unwrap());
+    }
+
+    #[test]
+    fn assign_and_unassign_a_ticket() {
+        let mut store = open_test_store("assign_and_unassign_a_ticket");
+        let draft = TicketDraft {
+            title: Title::new(Faker.fake()).expect("Failed to get a title"),
+            description: Faker.fake(),
+        };
+        let id = store.create(draft);
+        let assignee = Assignee::new("Ada Lovelace".to_string()).unwrap();
+
+        store.assign_ticket(id, Some(assignee.clone()));
+        assert_eq!(store.get(id).unwrap().assignee, Some(assignee));
+
+        store.assign_ticket(id, None);
+        assert_eq!(store.get(id).unwrap().assignee, None);
+    }
+
+    #[test]
+    fn delete_removes_the_ticket_and_its_comments() {
+        let mut store = open_test_store("delete_removes_the_ticket_and_its_comments");
+        let draft = TicketDraft {
+            title: Title::new(Faker.fake()).expect("Failed to get a title"),
+            description: Faker.fake(),
+        };
+        let id = store.create(draft);
+        store
+            .add_comment_to_ticket(id, crate::models::Comment::new("hi".to_string()).unwrap());
+
+        let deleted = store.delete(id);
+
+        assert!(deleted.is_some());
+        assert_eq!(store.get(id), None);
+    }
+
+    #[test]
+    fn list_returns_tickets_ordered_by_id() {
+        let mut store = open_test_store("list_returns_tickets_ordered_by_id");
+        let mut ids = Vec::new();
+        for _ in 0..3 {
+            let draft = TicketDraft {
+                title: Title::new(Faker.fake()).expect("Failed to get a title"),
+                description: Faker.fake(),
+            };
+            ids.push(store.create(draft));
+        }
+        ids.sort_unstable();
+
+        let listed_ids: Vec<_> = store.list().into_iter().map(|t| t.id).collect();
+
+        assert_eq!(listed_ids, ids);
+    }
+
+    #[test]
+    fn opening_a_database_created_before_assignees_existed_adds_the_columns() {
+        let path = std::env::temp_dir().join(format!(
+            "rusty_jira_test_pre_assignee_schema_{}.db",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+
+        // Simulate a tickets.db from before assignees were introduced: a
+        // tickets table with no assignee_id/assignee_name columns.
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(async {
+            let pool = sqlx::sqlite::SqlitePoolOptions::new()
+                .connect(&format!("sqlite://{}?mode=rwc", path.to_str().unwrap()))
+                .await
+                .unwrap();
+            sqlx::query(
+                "CREATE TABLE tickets (
+                    id TEXT PRIMARY KEY,
+                    title TEXT NOT NULL,
+                    description TEXT NOT NULL,
+                    status TEXT NOT NULL
+                )",
+            )
+            .execute(&pool)
+            .await
+            .unwrap();
+        });
+
+        let mut store = SqliteStore::connect(path.to_str().unwrap());
+        let draft = TicketDraft {
+            title: Title::new("Pre-existing board".to_string()).unwrap(),
+            description: "Should still work".to_string(),
+        };
+
+        let id = store.create(draft);
+
+        assert!(store.get(id).is_some());
+    }
+}